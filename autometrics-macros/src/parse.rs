@@ -34,6 +34,7 @@ mod kw {
     syn::custom_keyword!(alerts);
     syn::custom_keyword!(success_rate);
     syn::custom_keyword!(latency);
+    syn::custom_keyword!(name);
     syn::custom_keyword!(ok_if);
     syn::custom_keyword!(error_if);
 }
@@ -105,17 +106,21 @@ impl<T: Parse> Parse for ExprArg<T> {
 #[cfg(feature = "alerts")]
 mod alerts {
     use super::*;
+    use proc_macro2::TokenStream;
+    use quote::quote;
     use rust_decimal::Decimal;
-    use syn::{LitFloat, LitInt};
+    use syn::{LitFloat, LitInt, LitStr};
 
     #[cfg(feature = "alerts")]
     #[derive(Default, Debug)]
     pub(crate) struct Alerts {
+        pub name: Option<String>,
         pub success_rate: Option<Decimal>,
-        pub latency: Option<Latency>,
+        pub latency: Vec<Latency>,
     }
 
-    // Parse alerts in the form alerts(success_rate = 99.9%, latency(99.9% < 200ms))
+    // Parse alerts in the form
+    // alerts(name = "checkout", success_rate = 99.9%, latency(95% < 200ms), latency(99% < 500ms))
     impl Parse for Alerts {
         fn parse(input: ParseStream) -> Result<Self> {
             let content;
@@ -124,7 +129,14 @@ mod alerts {
             let mut alerts = Alerts::default();
             while !content.is_empty() {
                 let lookahead = content.lookahead1();
-                if lookahead.peek(kw::success_rate) {
+                if lookahead.peek(kw::name) {
+                    if alerts.name.is_some() {
+                        return Err(content.error("expected only a single `name` argument"));
+                    }
+                    let _ = content.parse::<kw::name>()?;
+                    let _ = content.parse::<Token![=]>()?;
+                    alerts.name = Some(content.parse::<LitStr>()?.value());
+                } else if lookahead.peek(kw::success_rate) {
                     let _ = content.parse::<kw::success_rate>()?;
 
                     let _ = content.parse::<Token![=]>()?;
@@ -134,7 +146,16 @@ mod alerts {
 
                     alerts.success_rate = Some(success_rate);
                 } else if lookahead.peek(kw::latency) {
-                    alerts.latency = Some(content.parse()?);
+                    let latency: Latency = content.parse()?;
+                    // Reject duplicate percentiles within a single objective.
+                    if alerts
+                        .latency
+                        .iter()
+                        .any(|existing| existing.percentile == latency.percentile)
+                    {
+                        return Err(content.error("duplicate latency percentile in `alerts`"));
+                    }
+                    alerts.latency.push(latency);
                 } else if lookahead.peek(Token![,]) {
                     let _ = content.parse::<Token![,]>()?;
                 } else {
@@ -145,6 +166,40 @@ mod alerts {
         }
     }
 
+    #[cfg(feature = "alerts")]
+    impl Alerts {
+        /// Expand the parsed SLO into the Prometheus recording/alerting rules the
+        /// instrumentation should register for this function.
+        ///
+        /// The objective name is emitted as the `objective_name` label on every
+        /// rule so functions sharing an SLO group together, and each `latency`
+        /// target produces its own rule — preserving the single-`latency` case as
+        /// a one-element expansion.
+        pub(crate) fn expand(&self) -> TokenStream {
+            let objective = self.name.clone().unwrap_or_default();
+
+            // Keep the label name in sync with `autometrics::constants::OBJECTIVE_NAME_KEY`;
+            // the macro crate cannot depend on the runtime crate, so it is spelled out here.
+            let mut rules: Vec<String> = Vec::new();
+            if let Some(success_rate) = self.success_rate {
+                rules.push(format!(
+                    "success_rate{{objective_name=\"{objective}\"}} >= {success_rate}"
+                ));
+            }
+            for latency in &self.latency {
+                rules.push(format!(
+                    "latency{{objective_name=\"{objective}\",percentile=\"{percentile}\"}} < {target}",
+                    percentile = latency.percentile,
+                    target = latency.target_seconds,
+                ));
+            }
+
+            quote! {
+                &[#(#rules),*]
+            }
+        }
+    }
+
     #[cfg(feature = "alerts")]
     #[derive(Debug)]
     pub(crate) struct Latency {
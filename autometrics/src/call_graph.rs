@@ -0,0 +1,84 @@
+//! Opt-in collection of the observed call graph.
+//!
+//! When the `call-graph` feature is enabled, every instrumented call records the
+//! `(caller → callee)` edge it traversed into a process-global, deduplicated set
+//! (see [`record_edge`]). Operators can then render the accumulated edges as a
+//! Graphviz `digraph` with [`dump_call_graph_dot`] to visualize the live
+//! architecture of their service straight from runtime data.
+//!
+//! Recording is insert-only: each distinct edge is stored exactly once and
+//! repeated calls along a known edge take a shared read lock and return without
+//! mutating anything. Since the number of instrumented functions is finite, the
+//! set stops growing after warmup and the steady state is lock-free of writers,
+//! so the collector stays cheap enough to leave on in production.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::sync::{OnceLock, RwLock};
+
+/// A fully-qualified instrumented function, identified by its module and name.
+use crate::caller::Node;
+
+/// The set of observed `(caller, callee)` edges. A `BTreeSet` keeps iteration
+/// order stable so the rendered DOT is deterministic.
+static EDGES: OnceLock<RwLock<BTreeSet<(Node, Node)>>> = OnceLock::new();
+
+fn edges() -> &'static RwLock<BTreeSet<(Node, Node)>> {
+    EDGES.get_or_init(|| RwLock::new(BTreeSet::new()))
+}
+
+/// Record that `caller` invoked `callee`.
+///
+/// Edges from an empty caller (a call with no instrumented parent on the stack)
+/// are ignored, since they represent the roots of the graph rather than a real
+/// caller/callee relationship. Already-known edges return under a shared read
+/// lock, so only the first observation of an edge ever takes the write lock.
+pub(crate) fn record_edge(caller: Node, callee: Node) {
+    if caller.0.is_empty() && caller.1.is_empty() {
+        return;
+    }
+    let edge = (caller, callee);
+
+    // Fast path: the edge is already known, so no mutation is needed.
+    if edges().read().unwrap().contains(&edge) {
+        return;
+    }
+    edges().write().unwrap().insert(edge);
+}
+
+/// Render the accumulated call graph as a Graphviz `digraph`.
+///
+/// Every instrumented function that appears in at least one recorded edge becomes
+/// a node, and every observed `(caller → callee)` relationship becomes an edge.
+/// Nodes are derived from the edges only: a function that has only been called
+/// without an instrumented caller, and has not itself called another
+/// instrumented function, has no recorded edge and does not appear.
+pub fn dump_call_graph_dot() -> String {
+    let edges = edges().read().unwrap();
+
+    let node_id = |node: Node| format!("{}::{}", node.0, node.1);
+
+    let mut out = String::from("digraph {\n");
+
+    // Emit a stable, deduplicated node list before the edges.
+    let mut nodes: BTreeSet<Node> = BTreeSet::new();
+    for (caller, callee) in edges.iter() {
+        nodes.insert(*caller);
+        nodes.insert(*callee);
+    }
+    for node in &nodes {
+        let _ = writeln!(out, "    {id:?};", id = node_id(*node));
+    }
+
+    for (caller, callee) in edges.iter() {
+        let _ = writeln!(
+            out,
+            "    {from:?} -> {to:?};",
+            from = node_id(*caller),
+            to = node_id(*callee),
+        );
+    }
+
+    out.push_str("}\n");
+    out
+}
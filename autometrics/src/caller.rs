@@ -0,0 +1,62 @@
+//! Caller tracking for building a runtime call graph.
+//!
+//! Each instrumented call peeks the top of a per-thread stack to learn its
+//! immediate caller, then pushes its own `(module, function)` frame for the
+//! duration of the call. A [`CallStackGuard`] pops the frame on `Drop`, so the
+//! stack is restored correctly even when the traced function returns early or
+//! panics.
+//!
+//! ## Scope: synchronous call nesting only
+//!
+//! Caller tracking reflects the **synchronous** call chain on the current
+//! thread and is only accurate for it. The frame a tracker pushes lives for the
+//! tracker's whole lifetime, which for an `async fn` spans its `.await` points,
+//! so this mechanism does **not** give correct caller attribution under an async
+//! executor: while one task is parked the same worker thread may poll an
+//! unrelated task, which would then observe the parked task's frame as its
+//! caller. Callers that need async-aware attribution should not rely on these
+//! edges. The guard pops unconditionally on drop, so a synchronous call — where
+//! the tracker is created and dropped on the same thread with no interleaving —
+//! always restores the stack exactly, with no leak.
+
+use std::cell::RefCell;
+
+/// A fully-qualified instrumented function, identified by its module and name.
+pub(crate) type Node = (&'static str, &'static str);
+
+thread_local! {
+    /// Stack of the instrumented functions synchronously executing on this
+    /// thread, stored as `(module, function)` pairs. The top is the immediate
+    /// caller of whatever function starts next.
+    static CALL_STACK: RefCell<Vec<Node>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the call stack when dropped, restoring it across early returns and
+/// panics.
+pub(crate) struct CallStackGuard;
+
+impl Drop for CallStackGuard {
+    fn drop(&mut self) {
+        CALL_STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+    }
+}
+
+/// Push `(module, function)` onto the current thread's call stack, returning the
+/// immediate caller (empty `Node` if there is none) and a guard that pops the
+/// frame when dropped.
+pub(crate) fn enter(module: &'static str, function: &'static str) -> (Node, CallStackGuard) {
+    let caller = CALL_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let caller = stack.last().copied().unwrap_or(("", ""));
+        stack.push((module, function));
+        caller
+    });
+
+    // Accumulate the observed call-graph edge for later Graphviz export.
+    #[cfg(feature = "call-graph")]
+    crate::call_graph::record_edge(caller, (module, function));
+
+    (caller, CallStackGuard)
+}
@@ -0,0 +1,29 @@
+//! Names, descriptions, units, and label keys for the metrics autometrics produces.
+
+pub const COUNTER_NAME: &str = "function.calls.count";
+pub const HISTOGRAM_NAME: &str = "function.calls.duration";
+pub const GAUGE_NAME: &str = "function.calls.concurrent";
+
+pub const COUNTER_DESCRIPTION: &str = "Autometrics counter for tracking function calls";
+pub const HISTOGRAM_DESCRIPTION: &str =
+    "Autometrics histogram for tracking function call duration";
+pub const GAUGE_DESCRIPTION: &str = "Autometrics gauge for tracking concurrent function calls";
+
+/// Units are expressed using the [UCUM](https://ucum.org/ucum) annotations that
+/// OpenTelemetry recommends, so that exporters emit correct `# UNIT` metadata
+/// regardless of which tracker is active.
+pub const COUNTER_UNIT: &str = "{call}";
+pub const HISTOGRAM_UNIT: &str = "s";
+pub const GAUGE_UNIT: &str = "{call}";
+
+pub const FUNCTION_KEY: &str = "function";
+pub const MODULE_KEY: &str = "module";
+
+/// Label carrying the name of the SLO objective, so a Prometheus recording or
+/// alerting rule can group every function that shares the same objective.
+pub const OBJECTIVE_NAME_KEY: &str = "objective_name";
+
+/// Labels describing the immediate caller of an instrumented function, used to
+/// build a runtime call graph from the `(caller → callee)` edge set.
+pub const CALLER_FUNCTION_KEY: &str = "caller_function";
+pub const CALLER_MODULE_KEY: &str = "caller_module";
@@ -0,0 +1,5 @@
+//! Label key/value pairs attached to the metrics autometrics produces.
+
+/// A single metric label as a `(key, value)` pair. Both halves are `'static`
+/// since they are derived from the instrumented function's identity.
+pub type Label = (&'static str, &'static str);
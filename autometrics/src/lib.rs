@@ -0,0 +1,25 @@
+//! Observability micro-framework that instruments functions with the most
+//! useful metrics — request rate, error rate, and latency — and exposes them
+//! through a pluggable backend.
+//!
+//! Exactly one backend feature should be enabled. With `opentelemetry` the
+//! metrics flow through `opentelemetry_api`; with `metrics` they flow through
+//! the [`metrics`](https://docs.rs/metrics) facade crate, so services already
+//! standardized on it don't have to pull in OpenTelemetry. The macro always
+//! instruments against [`tracker::AutometricsTracker`], which resolves to the
+//! active backend.
+
+pub mod constants;
+pub mod labels;
+pub mod tracker;
+
+pub(crate) mod caller;
+
+#[cfg(feature = "call-graph")]
+mod call_graph;
+
+#[cfg(feature = "call-graph")]
+pub use call_graph::dump_call_graph_dot;
+
+#[doc(hidden)]
+pub use tracker::AutometricsTracker;
@@ -0,0 +1,40 @@
+//! The [`TrackMetrics`] trait and the selection of the active backend.
+//!
+//! Autometrics can emit its metrics through more than one backend. Exactly one
+//! backend feature is expected to be enabled, and the macro instruments
+//! functions against [`AutometricsTracker`], the type alias that resolves to
+//! whichever backend is active — so callers on the `metrics` facade never have
+//! to pull in OpenTelemetry, and vice versa.
+
+use crate::labels::Label;
+
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
+
+#[cfg(feature = "metrics")]
+pub use metrics::MetricsTracker;
+#[cfg(feature = "opentelemetry")]
+pub use opentelemetry::OpenTelemetryTracker;
+
+/// Tracks the number of function calls, concurrent calls, and latency for a
+/// single instrumented call.
+pub trait TrackMetrics {
+    /// The function being instrumented.
+    fn function(&self) -> &'static str;
+    /// The module the instrumented function lives in.
+    fn module(&self) -> &'static str;
+    /// Start tracking a call, optionally incrementing the concurrency gauge.
+    fn start(function: &'static str, module: &'static str, track_concurrency: bool) -> Self;
+    /// Finish tracking a call, recording the counter (with `counter_labels`) and
+    /// the latency histogram.
+    fn finish(self, counter_labels: &[Label]);
+}
+
+/// The tracker the macro instruments against, selected by the enabled backend
+/// feature. OpenTelemetry takes precedence when both are enabled.
+#[cfg(feature = "opentelemetry")]
+pub type AutometricsTracker = OpenTelemetryTracker;
+#[cfg(all(feature = "metrics", not(feature = "opentelemetry")))]
+pub type AutometricsTracker = MetricsTracker;
@@ -0,0 +1,94 @@
+use crate::{caller, constants::*, labels::Label, tracker::TrackMetrics};
+use metrics::{
+    decrement_gauge, describe_counter, describe_gauge, describe_histogram, histogram,
+    increment_counter, increment_gauge, Label as MetricsLabel, Unit,
+};
+use std::time::Instant;
+
+/// Maps one of the shared UCUM unit constants onto the closest [`metrics::Unit`]
+/// variant. The `metrics` facade exposes a fixed enum rather than free-form UCUM
+/// strings, so the `{call}` annotation the OTel backend emits has no exact
+/// counterpart here and is reported as `Unit::Count`; `s` maps to `Unit::Seconds`.
+fn metrics_unit(unit: &str) -> Unit {
+    if unit == HISTOGRAM_UNIT {
+        Unit::Seconds
+    } else {
+        Unit::Count
+    }
+}
+
+/// Tracks the number of function calls, concurrent calls, and latency using the
+/// [`metrics`](https://docs.rs/metrics) facade crate.
+pub struct MetricsTracker {
+    module: &'static str,
+    function: &'static str,
+    track_concurrency: bool,
+    gauge_labels: [MetricsLabel; 2],
+    caller_labels: [MetricsLabel; 2],
+    start: Instant,
+    _call_stack_guard: caller::CallStackGuard,
+}
+
+impl TrackMetrics for MetricsTracker {
+    fn function(&self) -> &'static str {
+        self.function
+    }
+    fn module(&self) -> &'static str {
+        self.module
+    }
+
+    fn start(function: &'static str, module: &'static str, track_concurrency: bool) -> Self {
+        let gauge_labels = [
+            MetricsLabel::new(FUNCTION_KEY, function),
+            MetricsLabel::new(MODULE_KEY, module),
+        ];
+
+        // Capture the immediate caller (and push this function's frame) so the
+        // counter records a `(caller → callee)` edge. The guard pops the frame
+        // when this tracker is dropped.
+        let ((caller_module, caller_function), call_stack_guard) = caller::enter(module, function);
+        let caller_labels = [
+            MetricsLabel::new(CALLER_FUNCTION_KEY, caller_function),
+            MetricsLabel::new(CALLER_MODULE_KEY, caller_module),
+        ];
+
+        if track_concurrency {
+            // Increase the number of concurrent requests
+            describe_gauge!(GAUGE_NAME, metrics_unit(GAUGE_UNIT), GAUGE_DESCRIPTION);
+            increment_gauge!(GAUGE_NAME, 1.0, gauge_labels.to_vec());
+        }
+
+        Self {
+            function,
+            module,
+            track_concurrency,
+            gauge_labels,
+            caller_labels,
+            start: Instant::now(),
+            _call_stack_guard: call_stack_guard,
+        }
+    }
+
+    fn finish(self, counter_labels: &[Label]) {
+        let duration = self.start.elapsed().as_secs_f64();
+
+        // Track the function calls, attaching the caller labels so the counter
+        // captures the `(caller → callee)` edge alongside the existing labels.
+        let counter_labels: Vec<MetricsLabel> = counter_labels
+            .iter()
+            .map(|(k, v)| MetricsLabel::new(*k, *v))
+            .chain(self.caller_labels.iter().cloned())
+            .collect();
+        describe_counter!(COUNTER_NAME, metrics_unit(COUNTER_UNIT), COUNTER_DESCRIPTION);
+        increment_counter!(COUNTER_NAME, counter_labels);
+
+        // Track the latency
+        describe_histogram!(HISTOGRAM_NAME, metrics_unit(HISTOGRAM_UNIT), HISTOGRAM_DESCRIPTION);
+        histogram!(HISTOGRAM_NAME, duration, self.gauge_labels.to_vec());
+
+        // Decrease the number of concurrent requests
+        if self.track_concurrency {
+            decrement_gauge!(GAUGE_NAME, 1.0, self.gauge_labels.to_vec());
+        }
+    }
+}
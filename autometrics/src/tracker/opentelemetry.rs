@@ -1,5 +1,5 @@
-use crate::{constants::*, labels::Label, tracker::TrackMetrics};
-use opentelemetry_api::{global, metrics::UpDownCounter, Context, KeyValue};
+use crate::{caller, constants::*, labels::Label, tracker::TrackMetrics};
+use opentelemetry_api::{global, metrics::Unit, metrics::UpDownCounter, Context, KeyValue};
 use std::time::Instant;
 
 /// Tracks the number of function calls, concurrent calls, and latency
@@ -8,8 +8,10 @@ pub struct OpenTelemetryTracker {
     function: &'static str,
     concurrency_tracker: Option<UpDownCounter<i64>>,
     function_and_module_labels: [KeyValue; 2],
+    caller_labels: [KeyValue; 2],
     start: Instant,
     context: Context,
+    _call_stack_guard: caller::CallStackGuard,
 }
 
 impl TrackMetrics for OpenTelemetryTracker {
@@ -26,12 +28,22 @@ impl TrackMetrics for OpenTelemetryTracker {
             KeyValue::new(MODULE_KEY, module),
         ];
 
+        // Capture the immediate caller (and push this function's frame) so the
+        // counter records a `(caller → callee)` edge. The guard pops the frame
+        // when this tracker is dropped.
+        let ((caller_module, caller_function), call_stack_guard) = caller::enter(module, function);
+        let caller_labels = [
+            KeyValue::new(CALLER_FUNCTION_KEY, caller_function),
+            KeyValue::new(CALLER_MODULE_KEY, caller_module),
+        ];
+
         let context = Context::current();
         let concurrency_tracker = if track_concurrency {
             // Increase the number of concurrent requests
             let concurrency_tracker = global::meter("")
                 .i64_up_down_counter(GAUGE_NAME)
                 .with_description(GAUGE_DESCRIPTION)
+                .with_unit(Unit::new(GAUGE_UNIT))
                 .init();
             concurrency_tracker.add(&context, 1, &function_and_module_labels);
             Some(concurrency_tracker)
@@ -43,23 +55,28 @@ impl TrackMetrics for OpenTelemetryTracker {
             function,
             module,
             function_and_module_labels,
+            caller_labels,
             concurrency_tracker,
             start: Instant::now(),
             context,
+            _call_stack_guard: call_stack_guard,
         }
     }
 
-    fn finish<'a>(self, counter_labels: &[Label]) {
+    fn finish(self, counter_labels: &[Label]) {
         let duration = self.start.elapsed().as_secs_f64();
 
-        // Track the function calls
+        // Track the function calls, attaching the caller labels so the counter
+        // captures the `(caller → callee)` edge alongside the existing labels.
         let counter_labels: Vec<KeyValue> = counter_labels
             .into_iter()
             .map(|(k, v)| KeyValue::new(*k, *v))
+            .chain(self.caller_labels.iter().cloned())
             .collect();
         let counter = global::meter("")
             .f64_counter(COUNTER_NAME)
             .with_description(COUNTER_DESCRIPTION)
+            .with_unit(Unit::new(COUNTER_UNIT))
             .init();
         counter.add(&self.context, 1.0, &counter_labels);
 
@@ -67,6 +84,7 @@ impl TrackMetrics for OpenTelemetryTracker {
         let histogram = global::meter("")
             .f64_histogram(HISTOGRAM_NAME)
             .with_description(HISTOGRAM_DESCRIPTION)
+            .with_unit(Unit::new(HISTOGRAM_UNIT))
             .init();
         histogram.record(&self.context, duration, &self.function_and_module_labels);
 